@@ -0,0 +1,103 @@
+use parking_lot::RwLock;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::hash::{BuildHasherDefault, Hasher};
+
+/// `Hasher` for `TypeId` keys that skips SipHash, since a `TypeId` is already a unique id with
+/// nothing left to mix. `TypeId::hash` can feed more than one 64-bit word into the hasher (its
+/// internal representation is wider than 64 bits), so each word is folded into the running state
+/// with `rotate_left`+`xor` rather than overwriting the previous one, which would silently drop
+/// all but the last word and collide whenever two `TypeId`s share it.
+#[derive(Default)]
+pub struct TypeIdHasher(u64);
+
+impl Hasher for TypeIdHasher {
+    fn write(&mut self, _bytes: &[u8]) {
+        unreachable!("TypeIdHasher only supports hashing TypeId via write_u64")
+    }
+
+    fn write_u64(&mut self, n: u64) {
+        self.0 = self.0.rotate_left(32) ^ n;
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Stores at most one value per Rust type, keyed by `TypeId`.
+///
+/// This deliberately does not go through [`crate::parking_lot::RwMutexMap`]: it and its
+/// [`crate::traits::KeyValueStore`] impl require `V: Clone`, which `Box<dyn Any + Send + Sync>`
+/// cannot satisfy, so `AnyStore`
+/// keeps its own lock around a `HashMap` instead, hashed with [`TypeIdHasher`].
+pub struct AnyStore {
+    inner: RwLock<HashMap<TypeId, Box<dyn Any + Send + Sync>, BuildHasherDefault<TypeIdHasher>>>,
+}
+
+impl AnyStore {
+    pub fn new() -> Self {
+        Self {
+            inner: RwLock::new(HashMap::default()),
+        }
+    }
+
+    /// Inserts a value keyed by its own type, returning the previous value of that type if any.
+    pub async fn insert_typed<T: Any + Send + Sync>(&self, value: T) -> Option<T> {
+        let mut map = self.inner.write();
+        map.insert(TypeId::of::<T>(), Box::new(value))
+            .and_then(|old| old.downcast::<T>().ok())
+            .map(|boxed| *boxed)
+    }
+
+    /// Gets a clone of the stored value of type `T`, if one is present.
+    pub async fn get_typed<T: Any + Send + Sync + Clone>(&self) -> Option<T> {
+        let map = self.inner.read();
+        map.get(&TypeId::of::<T>())
+            .and_then(|boxed| boxed.downcast_ref::<T>())
+            .cloned()
+    }
+
+    /// Runs `f` against the stored value of type `T`, if one is present. Returns `true` if the
+    /// value existed and was mutated.
+    pub async fn with_typed<T, F>(&self, mut f: F) -> bool
+    where
+        T: Any + Send + Sync,
+        F: FnMut(&mut T) + Send,
+    {
+        let mut map = self.inner.write();
+        match map.get_mut(&TypeId::of::<T>()).and_then(|boxed| boxed.downcast_mut::<T>()) {
+            Some(value) => {
+                f(value);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl Default for AnyStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_any_store() {
+    let store = AnyStore::new();
+
+    assert_eq!(store.insert_typed(1u32).await, None);
+    assert_eq!(store.insert_typed("hello".to_string()).await, None);
+
+    assert_eq!(store.get_typed::<u32>().await, Some(1));
+    assert_eq!(store.get_typed::<String>().await, Some("hello".to_string()));
+    assert_eq!(store.get_typed::<i64>().await, None);
+
+    assert_eq!(store.insert_typed(2u32).await, Some(1));
+
+    assert!(store.with_typed::<u32, _>(|v| *v += 10).await);
+    assert_eq!(store.get_typed::<u32>().await, Some(12));
+
+    assert!(!store.with_typed::<i64, _>(|v| *v += 1).await);
+}
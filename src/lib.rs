@@ -7,6 +7,18 @@ pub mod traits;
 #[cfg(feature = "impl")]
 pub mod parking_lot;
 
+#[cfg(feature = "impl")]
+pub mod loader;
+
+#[cfg(feature = "impl")]
+pub mod any_store;
+
+#[cfg(feature = "impl")]
+pub mod sharded;
+
+#[cfg(feature = "im")]
+pub mod persistent;
+
 #[cfg(test)]
 pub mod test {
 
@@ -0,0 +1,269 @@
+use crate::traits::{GetOwned, KeyValueStore};
+use async_trait::async_trait;
+use parking_lot::Mutex as SyncMutex;
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fmt;
+use std::hash::Hash;
+use tokio::sync::oneshot;
+
+/// User-supplied bulk fetcher used by [`Loader`] to resolve a batch of missing keys in one call.
+#[async_trait]
+pub trait BatchFn<K, V>
+where
+    K: Send + Sync,
+    V: Send + Sync,
+{
+    type Err;
+    /// Fetches every value for the given keys. Keys absent from the returned map are treated
+    /// as "not found" by every waiter for that key.
+    async fn load_batch(&self, keys: &[K]) -> Result<HashMap<K, V>, Self::Err>;
+}
+
+/// A key that a [`BatchFn`] was asked to load but didn't come back in its result.
+#[derive(Debug)]
+pub struct MissingFromBatch;
+
+impl fmt::Display for MissingFromBatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "key missing from batch result")
+    }
+}
+
+impl Error for MissingFromBatch {}
+
+/// Returned to a waiter whose key was taken for dispatch but never answered, which happens when
+/// the `load`/`load_many` call running that dispatch is dropped before the batch completes.
+#[derive(Debug)]
+pub struct LoaderDropped;
+
+impl fmt::Display for LoaderDropped {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "loader dropped before resolving")
+    }
+}
+
+impl Error for LoaderDropped {}
+
+type Waiter<V> = oneshot::Sender<Result<V, Box<dyn Error>>>;
+type WaiterReceiver<V> = oneshot::Receiver<Result<V, Box<dyn Error>>>;
+
+/// Request-coalescing batch loader that sits in front of a [`KeyValueStore`], mirroring the
+/// dataloader pattern: many concurrent misses for the same or different keys are coalesced into
+/// a single call to the underlying [`BatchFn`], and the fetched values are written back into the
+/// store so later reads hit it directly.
+///
+/// `KeyValueStore`'s `contains`/`inspect`/`get_mut`/`remove` are generic over a borrowed key
+/// type, which makes the trait itself unable to form a trait object; `Loader` is therefore
+/// generic over a concrete store `S` rather than holding a `dyn KeyValueStore`.
+pub struct Loader<K, V, S, F>
+where
+    K: Ord + Hash + Send + Sync,
+    V: Clone + Send + Sync,
+{
+    store: S,
+    batch_fn: F,
+    pending: SyncMutex<BTreeMap<K, Vec<Waiter<V>>>>,
+}
+
+impl<K, V, S, F> Loader<K, V, S, F>
+where
+    K: Ord + Hash + Clone + Send + Sync + 'static,
+    V: Clone + Send + Sync + 'static,
+    S: KeyValueStore<K, V, Err = Box<dyn Error>> + Send + Sync,
+    F: BatchFn<K, V> + Send + Sync,
+    F::Err: Error + 'static,
+{
+    pub fn new(store: S, batch_fn: F) -> Self {
+        Self {
+            store,
+            batch_fn,
+            pending: SyncMutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Loads a single key, coalescing this call with any other concurrent misses for the same
+    /// dispatch window into one `load_batch` call.
+    pub async fn load(&self, key: K) -> Result<V, Box<dyn Error>> {
+        if let Ok(Some(v)) = self.store.get_owned(&key).await {
+            return Ok(v);
+        }
+
+        let (should_dispatch, rx) = self.enqueue(key);
+        if should_dispatch {
+            // Let any other callers queued up in this task-yield register before we drain.
+            tokio::task::yield_now().await;
+            self.dispatch().await;
+        }
+
+        rx.await.unwrap_or_else(|_| Err(Box::new(LoaderDropped)))
+    }
+
+    /// Loads many keys at once. Every key is checked against the store and, if still missing,
+    /// registered as a waiter before anything yields, so all of this call's misses land in the
+    /// same dispatch window and are resolved by a single `load_batch` call.
+    pub async fn load_many(&self, keys: Vec<K>) -> Vec<Result<V, Box<dyn Error>>> {
+        enum Slot<V> {
+            Ready(Result<V, Box<dyn Error>>),
+            Pending(WaiterReceiver<V>),
+        }
+
+        let mut slots = Vec::with_capacity(keys.len());
+        let mut should_dispatch = false;
+        for key in keys {
+            if let Ok(Some(v)) = self.store.get_owned(&key).await {
+                slots.push(Slot::Ready(Ok(v)));
+                continue;
+            }
+            let (first_waiter, rx) = self.enqueue(key);
+            should_dispatch |= first_waiter;
+            slots.push(Slot::Pending(rx));
+        }
+
+        if should_dispatch {
+            tokio::task::yield_now().await;
+            self.dispatch().await;
+        }
+
+        let mut out = Vec::with_capacity(slots.len());
+        for slot in slots {
+            out.push(match slot {
+                Slot::Ready(v) => v,
+                Slot::Pending(rx) => rx.await.unwrap_or_else(|_| Err(Box::new(LoaderDropped))),
+            });
+        }
+        out
+    }
+
+    /// Registers a waiter for `key`, returning whether this call is the first to register since
+    /// the last dispatch (and so is responsible for scheduling the next one).
+    fn enqueue(&self, key: K) -> (bool, WaiterReceiver<V>) {
+        let (tx, rx) = oneshot::channel();
+        let mut pending = self.pending.lock();
+        let first_waiter = pending.is_empty();
+        pending.entry(key).or_default().push(tx);
+        (first_waiter, rx)
+    }
+
+    async fn dispatch(&self) {
+        let waiters: BTreeMap<K, Vec<Waiter<V>>> = {
+            let mut pending = self.pending.lock();
+            std::mem::take(&mut *pending)
+        };
+
+        if waiters.is_empty() {
+            return;
+        }
+
+        let keys: Vec<K> = waiters.keys().cloned().collect();
+        match self.batch_fn.load_batch(&keys).await {
+            Ok(mut results) => {
+                for (key, senders) in waiters {
+                    let value = results.remove(&key);
+                    for sender in senders {
+                        let reply = match &value {
+                            Some(v) => Ok(v.clone()),
+                            None => Err(Box::new(MissingFromBatch) as Box<dyn Error>),
+                        };
+                        let _ = sender.send(reply);
+                    }
+                    if let Some(v) = value {
+                        let _ = self.store.insert(key, v).await;
+                    }
+                }
+            }
+            Err(err) => {
+                let message = err.to_string();
+                for (_, senders) in waiters {
+                    for sender in senders {
+                        let _ = sender.send(Err(message.clone().into()));
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+struct CountingBatch {
+    calls: std::sync::atomic::AtomicUsize,
+}
+
+#[cfg(test)]
+#[async_trait]
+impl BatchFn<u32, String> for CountingBatch {
+    type Err = std::convert::Infallible;
+
+    async fn load_batch(&self, keys: &[u32]) -> Result<HashMap<u32, String>, Self::Err> {
+        self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        Ok(keys
+            .iter()
+            .filter(|k| **k != 404)
+            .map(|k| (*k, format!("value-{k}")))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_loader_coalesces_concurrent_misses() {
+    use crate::parking_lot::RwMutexMap;
+
+    let loader = Loader::new(
+        RwMutexMap::new(),
+        CountingBatch {
+            calls: std::sync::atomic::AtomicUsize::new(0),
+        },
+    );
+
+    let (a, b, c) = tokio::join!(loader.load(1), loader.load(1), loader.load(2));
+
+    assert_eq!(a.unwrap(), "value-1".to_string());
+    assert_eq!(b.unwrap(), "value-1".to_string());
+    assert_eq!(c.unwrap(), "value-2".to_string());
+    assert_eq!(
+        loader.batch_fn.calls.load(std::sync::atomic::Ordering::SeqCst),
+        1
+    );
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_loader_fans_out_missing_key_as_error_to_every_waiter() {
+    use crate::parking_lot::RwMutexMap;
+
+    let loader = Loader::new(
+        RwMutexMap::new(),
+        CountingBatch {
+            calls: std::sync::atomic::AtomicUsize::new(0),
+        },
+    );
+
+    let (first, second) = tokio::join!(loader.load(404), loader.load(404));
+    assert!(first.is_err());
+    assert!(second.is_err());
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_loader_load_many_coalesces_into_a_single_batch_call() {
+    use crate::parking_lot::RwMutexMap;
+
+    let loader = Loader::new(
+        RwMutexMap::new(),
+        CountingBatch {
+            calls: std::sync::atomic::AtomicUsize::new(0),
+        },
+    );
+
+    let results = loader.load_many(vec![1, 2, 3, 404]).await;
+
+    assert_eq!(results[0].as_ref().unwrap(), "value-1");
+    assert_eq!(results[1].as_ref().unwrap(), "value-2");
+    assert_eq!(results[2].as_ref().unwrap(), "value-3");
+    assert!(results[3].is_err());
+    assert_eq!(
+        loader.batch_fn.calls.load(std::sync::atomic::Ordering::SeqCst),
+        1
+    );
+}
@@ -1,7 +1,7 @@
 use crate::traits::KeyValueStore;
 use async_trait::async_trait;
 use parking_lot::{Mutex, RwLock};
-use std::{collections::BTreeMap, error::Error, sync::Arc};
+use std::{borrow::Borrow, collections::BTreeMap, error::Error, hash::Hash, sync::Arc};
 
 /// Read write locked BTreeMap, that reduces WriteLocks by only using them when adding new keys, or removing keys,
 /// But not when updating already existing values, or adding to an already added key
@@ -20,10 +20,58 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
+impl<K, V> RwMutexMap<K, V>
+where
+    K: Ord + Clone + serde::Serialize + serde::de::DeserializeOwned,
+    V: Clone + serde::Serialize + serde::de::DeserializeOwned,
+{
+    /// Serializes the whole store to bytes. Holds one write lock for the whole collection, so
+    /// the snapshot reflects a single consistent point in time rather than a torn mix of states.
+    pub async fn snapshot(&self) -> Result<Vec<u8>, Box<dyn Error>> {
+        let map: BTreeMap<K, V> = {
+            let guard = self.0.write();
+            guard
+                .iter()
+                .map(|(k, v)| (k.clone(), v.lock().clone()))
+                .collect()
+        };
+        Ok(serde_json::to_vec(&map)?)
+    }
+
+    /// Replaces the store's contents with the data from a previous [`RwMutexMap::snapshot`].
+    pub async fn restore(&self, data: &[u8]) -> Result<(), Box<dyn Error>> {
+        let map: BTreeMap<K, V> = serde_json::from_slice(data)?;
+        let mut guard = self.0.write();
+        *guard = map
+            .into_iter()
+            .map(|(k, v)| (k, Arc::new(Mutex::new(v))))
+            .collect();
+        Ok(())
+    }
+
+    /// Writes a snapshot to `path` via a temp file plus rename, so a reader opening `path`
+    /// never sees a half-written file.
+    pub async fn save_to_path(&self, path: impl AsRef<std::path::Path>) -> Result<(), Box<dyn Error>> {
+        let path = path.as_ref();
+        let data = self.snapshot().await?;
+        let tmp_path = path.with_extension("tmp");
+        tokio::fs::write(&tmp_path, &data).await?;
+        tokio::fs::rename(&tmp_path, path).await?;
+        Ok(())
+    }
+
+    /// Restores the store from a snapshot file written by [`RwMutexMap::save_to_path`].
+    pub async fn load_from_path(&self, path: impl AsRef<std::path::Path>) -> Result<(), Box<dyn Error>> {
+        let data = tokio::fs::read(path.as_ref()).await?;
+        self.restore(&data).await
+    }
+}
+
 #[async_trait]
 impl<K, V> KeyValueStore<K, V> for RwMutexMap<K, V>
 where
-    K: Ord + Send + Sync,
+    K: Ord + Hash + Send + Sync,
     V: Clone + Send + Sync,
 {
     type Err = Box<dyn Error>;
@@ -52,7 +100,11 @@ where
         }
     }
 
-    async fn remove(&self, key: &K) -> Result<Option<V>, Self::Err> {
+    async fn remove<Q>(&self, key: &Q) -> Result<Option<V>, Self::Err>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Ord + Send + Sync + ?Sized,
+    {
         let mut map = self.0.write();
         let rm = map.remove(key);
         match rm {
@@ -64,13 +116,19 @@ where
         }
     }
 
-    async fn contains(&self, key: &K) -> Result<bool, Self::Err> {
+    async fn contains<Q>(&self, key: &Q) -> Result<bool, Self::Err>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Ord + Send + Sync + ?Sized,
+    {
         let map = self.0.read();
         Ok(map.contains_key(key))
     }
 
-    async fn get_mut<F>(&self, key: &K, mut f: F) -> Result<bool, Self::Err>
+    async fn get_mut<Q, F>(&self, key: &Q, mut f: F) -> Result<bool, Self::Err>
     where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Ord + Send + Sync + ?Sized,
         F: FnMut(&mut V) + Send,
     {
         let map = self.0.read();
@@ -111,8 +169,10 @@ where
         Ok(())
     }
 
-    async fn inspect<F>(&self, key: &K, mut f: F) -> Result<(), Self::Err>
+    async fn inspect<Q, F>(&self, key: &Q, mut f: F) -> Result<(), Self::Err>
     where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Ord + Send + Sync + ?Sized,
         F: FnMut(Option<&V>) + Send,
     {
         let map = self.0.read();
@@ -132,7 +192,7 @@ where
 impl<T, K, V> KeyValueStore<K, V> for Arc<T>
 where
     T: KeyValueStore<K, V> + Send + Sync,
-    K: Ord + Send + Sync + 'static,
+    K: Ord + Hash + Send + Sync + 'static,
     V: Clone + Send + Sync + 'static,
 {
     type Err = <T as KeyValueStore<K, V>>::Err;
@@ -140,14 +200,24 @@ where
     async fn insert(&self, key: K, value: V) -> Result<Option<V>, Self::Err> {
         Ok(T::insert(self, key, value).await?)
     }
-    async fn remove(&self, key: &K) -> Result<Option<V>, Self::Err> {
+    async fn remove<Q>(&self, key: &Q) -> Result<Option<V>, Self::Err>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Ord + Send + Sync + ?Sized,
+    {
         Ok(T::remove(self, key).await?)
     }
-    async fn contains(&self, key: &K) -> Result<bool, Self::Err> {
+    async fn contains<Q>(&self, key: &Q) -> Result<bool, Self::Err>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Ord + Send + Sync + ?Sized,
+    {
         Ok(T::contains(self, key).await?)
     }
-    async fn get_mut<F>(&self, key: &K, f: F) -> Result<bool, Self::Err>
+    async fn get_mut<Q, F>(&self, key: &Q, f: F) -> Result<bool, Self::Err>
     where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Ord + Send + Sync + ?Sized,
         F: FnMut(&mut V) + Send,
     {
         Ok(T::get_mut(self, key, f).await?)
@@ -164,8 +234,10 @@ where
     {
         Ok(T::for_each_mut(self, f).await?)
     }
-    async fn inspect<F>(&self, key: &K, f: F) -> Result<(), Self::Err>
+    async fn inspect<Q, F>(&self, key: &Q, f: F) -> Result<(), Self::Err>
     where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Ord + Send + Sync + ?Sized,
         F: FnMut(Option<&V>) + Send,
     {
         Ok(T::inspect(self, key, f).await?)
@@ -180,3 +252,40 @@ async fn test_rwmutexmap() {
     let kvstore = Arc::new(RwMutexMap::new());
     crate::test::test_impl(&kvstore).await;
 }
+
+#[cfg(all(test, feature = "serde"))]
+#[tokio::test]
+async fn test_rwmutexmap_snapshot_restore_round_trip() {
+    use crate::traits::GetOwned;
+
+    let kvstore: RwMutexMap<usize, String> = RwMutexMap::new();
+    kvstore.insert(1, "hello".to_string()).await.unwrap();
+    kvstore.insert(2, "world".to_string()).await.unwrap();
+
+    let data = kvstore.snapshot().await.unwrap();
+
+    let restored: RwMutexMap<usize, String> = RwMutexMap::new();
+    restored.restore(&data).await.unwrap();
+
+    assert_eq!(restored.get_owned(&1).await.unwrap(), Some("hello".to_string()));
+    assert_eq!(restored.get_owned(&2).await.unwrap(), Some("world".to_string()));
+}
+
+#[cfg(all(test, feature = "serde"))]
+#[tokio::test]
+async fn test_rwmutexmap_save_load_path_round_trip() {
+    use crate::traits::GetOwned;
+
+    let path = std::env::temp_dir().join(format!("kv_store_test_{}.snapshot", std::process::id()));
+
+    let kvstore: RwMutexMap<usize, String> = RwMutexMap::new();
+    kvstore.insert(1, "hello".to_string()).await.unwrap();
+    kvstore.save_to_path(&path).await.unwrap();
+
+    let loaded: RwMutexMap<usize, String> = RwMutexMap::new();
+    loaded.load_from_path(&path).await.unwrap();
+
+    assert_eq!(loaded.get_owned(&1).await.unwrap(), Some("hello".to_string()));
+
+    let _ = std::fs::remove_file(&path);
+}
@@ -0,0 +1,181 @@
+use crate::traits::KeyValueStore;
+use async_trait::async_trait;
+use parking_lot::RwLock;
+use std::{error::Error, sync::Arc};
+
+/// `KeyValueStore` backed by an immutable hash-array-mapped-trie (`im::HashMap`), so that
+/// [`PersistentMap::snapshot`] is an `O(1)`, structurally-shared copy rather than the deep clone
+/// that [`crate::traits::GetBTreeMap::btreemap`]/[`crate::traits::GetHashMap::hashmap`] perform
+/// over the other stores. The map sits behind a single `RwLock`: reads hold the read lock for the
+/// whole call and do not work on a detached copy of the root, and writes mutate the map in place
+/// under the write lock. Because `im::HashMap` nodes are reference-counted, a write copies only
+/// the nodes it touches that a snapshot still shares, leaving that snapshot unchanged.
+pub struct PersistentMap<K, V>
+where
+    K: std::hash::Hash + Eq + Clone,
+    V: Clone,
+{
+    root: RwLock<im::HashMap<K, V>>,
+}
+
+impl<K, V> PersistentMap<K, V>
+where
+    K: std::hash::Hash + Eq + Clone,
+    V: Clone,
+{
+    pub fn new() -> Self {
+        Self {
+            root: RwLock::new(im::HashMap::new()),
+        }
+    }
+
+    /// Returns an immutable, point-in-time handle to the store's contents. The snapshot shares
+    /// structure with the live map and is unaffected by mutations made after it was taken,
+    /// making it suitable for consistent backups or concurrent iteration while writers continue.
+    pub async fn snapshot(&self) -> PersistentMapSnapshot<K, V> {
+        PersistentMapSnapshot(self.root.read().clone())
+    }
+}
+
+impl<K, V> Default for PersistentMap<K, V>
+where
+    K: std::hash::Hash + Eq + Clone,
+    V: Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An immutable, structurally-shared snapshot of a [`PersistentMap`] taken at a point in time.
+pub struct PersistentMapSnapshot<K, V>(im::HashMap<K, V>)
+where
+    K: std::hash::Hash + Eq + Clone,
+    V: Clone;
+
+impl<K, V> PersistentMapSnapshot<K, V>
+where
+    K: std::hash::Hash + Eq + Clone,
+    V: Clone,
+{
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.0.get(key)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[async_trait]
+impl<K, V> KeyValueStore<K, V> for PersistentMap<K, V>
+where
+    K: std::hash::Hash + Eq + Ord + Clone + Send + Sync,
+    V: Clone + Send + Sync,
+{
+    type Err = Box<dyn Error>;
+
+    async fn insert(&self, key: K, value: V) -> Result<Option<V>, Self::Err> {
+        let mut root = self.root.write();
+        Ok(root.insert(key, value))
+    }
+
+    async fn remove<Q>(&self, key: &Q) -> Result<Option<V>, Self::Err>
+    where
+        K: std::borrow::Borrow<Q>,
+        Q: std::hash::Hash + Eq + Ord + Send + Sync + ?Sized,
+    {
+        let mut root = self.root.write();
+        Ok(root.remove(key))
+    }
+
+    async fn contains<Q>(&self, key: &Q) -> Result<bool, Self::Err>
+    where
+        K: std::borrow::Borrow<Q>,
+        Q: std::hash::Hash + Eq + Ord + Send + Sync + ?Sized,
+    {
+        let root = self.root.read();
+        Ok(root.contains_key(key))
+    }
+
+    async fn get_mut<Q, F>(&self, key: &Q, mut f: F) -> Result<bool, Self::Err>
+    where
+        K: std::borrow::Borrow<Q>,
+        Q: std::hash::Hash + Eq + Ord + Send + Sync + ?Sized,
+        F: FnMut(&mut V) + Send,
+    {
+        let mut root = self.root.write();
+        match root.get_mut(key) {
+            Some(v) => {
+                f(v);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn inspect<Q, F>(&self, key: &Q, mut f: F) -> Result<(), Self::Err>
+    where
+        K: std::borrow::Borrow<Q>,
+        Q: std::hash::Hash + Eq + Ord + Send + Sync + ?Sized,
+        F: FnMut(Option<&V>) + Send,
+    {
+        let root = self.root.read();
+        f(root.get(key));
+        Ok(())
+    }
+
+    async fn for_each<F>(&self, mut f: F) -> Result<(), Self::Err>
+    where
+        F: FnMut((&K, &V)) + Send,
+    {
+        let root = self.root.read();
+        root.iter().for_each(|(k, v)| f((k, v)));
+        Ok(())
+    }
+
+    async fn for_each_mut<F>(&self, mut f: F) -> Result<(), Self::Err>
+    where
+        F: FnMut((&K, &mut V)) + Send,
+    {
+        let mut root = self.root.write();
+        root.iter_mut().for_each(|(k, v)| f((k, v)));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_persistentmap() {
+    let kvstore = PersistentMap::new();
+    crate::test::test_impl(&kvstore).await;
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_persistentmap_snapshot_is_unaffected_by_later_writes() {
+    let kvstore: PersistentMap<usize, String> = PersistentMap::new();
+    kvstore.insert(1, "before".to_string()).await.unwrap();
+
+    let snapshot = kvstore.snapshot().await;
+
+    kvstore.insert(1, "after".to_string()).await.unwrap();
+    kvstore.insert(2, "new".to_string()).await.unwrap();
+
+    assert_eq!(snapshot.get(&1), Some(&"before".to_string()));
+    assert_eq!(snapshot.get(&2), None);
+    assert_eq!(snapshot.len(), 1);
+
+    kvstore
+        .inspect(&1, |v| assert_eq!(v, Some(&"after".to_string())))
+        .await
+        .unwrap();
+}
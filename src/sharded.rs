@@ -0,0 +1,225 @@
+use crate::traits::KeyValueStore;
+use async_trait::async_trait;
+use parking_lot::{Mutex, RwLock};
+use std::{
+    borrow::Borrow,
+    collections::BTreeMap,
+    collections::hash_map::DefaultHasher,
+    error::Error,
+    hash::{Hash, Hasher},
+    sync::Arc,
+};
+
+/// Drop-in [`KeyValueStore`] replacement for [`crate::parking_lot::RwMutexMap`] that spreads keys
+/// over several independently-locked sub-maps, so an insert or remove on one shard doesn't stall
+/// readers and writers working on a different shard.
+///
+/// Each shard is a `BTreeMap`, which has no notion of capacity, so there is intentionally no
+/// `reserve` method here: a no-op public method would silently do nothing while looking like it
+/// pre-allocates. Switch to a hash-map-backed shard if that capability is ever needed.
+pub struct ShardedMap<K, V>
+where
+    K: Ord + Hash,
+    V: Clone,
+{
+    shards: Vec<RwLock<BTreeMap<K, Arc<Mutex<V>>>>>,
+}
+
+impl<K, V> ShardedMap<K, V>
+where
+    K: Ord + Hash,
+    V: Clone,
+{
+    /// Creates a store with `num_cpus::get() * 4` shards.
+    pub fn new() -> Self {
+        Self::with_shards(num_cpus::get() * 4)
+    }
+
+    /// Creates a store with exactly `shard_count` shards (clamped to at least 1).
+    pub fn with_shards(shard_count: usize) -> Self {
+        let shard_count = shard_count.max(1);
+        let shards = (0..shard_count).map(|_| RwLock::new(BTreeMap::new())).collect();
+        Self { shards }
+    }
+
+    fn shard_for<Q>(&self, key: &Q) -> &RwLock<BTreeMap<K, Arc<Mutex<V>>>>
+    where
+        K: Borrow<Q>,
+        Q: Hash + ?Sized,
+    {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        let index = (hasher.finish() as usize) % self.shards.len();
+        &self.shards[index]
+    }
+
+    /// Total number of entries across all shards.
+    pub async fn len(&self) -> usize {
+        self.shards.iter().map(|shard| shard.read().len()).sum()
+    }
+
+    pub async fn is_empty(&self) -> bool {
+        self.len().await == 0
+    }
+}
+
+impl<K, V> Default for ShardedMap<K, V>
+where
+    K: Ord + Hash,
+    V: Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl<K, V> KeyValueStore<K, V> for ShardedMap<K, V>
+where
+    K: Ord + Hash + Send + Sync,
+    V: Clone + Send + Sync,
+{
+    type Err = Box<dyn Error>;
+
+    async fn insert(&self, key: K, value: V) -> Result<Option<V>, Self::Err> {
+        if self.contains(&key).await? {
+            let mut old = None;
+            self.get_mut(&key, |ov| {
+                old = Some(ov.clone());
+                *ov = value.clone();
+            })
+            .await?;
+
+            Ok(old)
+        } else {
+            let mut shard = self.shard_for(&key).write();
+            match shard.insert(key, Arc::new(Mutex::new(value))) {
+                Some(v) => Ok(Some(v.lock().clone())),
+                None => Ok(None),
+            }
+        }
+    }
+
+    async fn remove<Q>(&self, key: &Q) -> Result<Option<V>, Self::Err>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Ord + Send + Sync + ?Sized,
+    {
+        let mut shard = self.shard_for(key).write();
+        Ok(shard.remove(key).map(|v| v.lock().clone()))
+    }
+
+    async fn contains<Q>(&self, key: &Q) -> Result<bool, Self::Err>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Ord + Send + Sync + ?Sized,
+    {
+        let shard = self.shard_for(key).read();
+        Ok(shard.contains_key(key))
+    }
+
+    async fn get_mut<Q, F>(&self, key: &Q, mut f: F) -> Result<bool, Self::Err>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Ord + Send + Sync + ?Sized,
+        F: FnMut(&mut V) + Send,
+    {
+        let shard = self.shard_for(key).read();
+        match shard.get(key) {
+            Some(v) => {
+                f(&mut v.lock());
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn inspect<Q, F>(&self, key: &Q, mut f: F) -> Result<(), Self::Err>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Ord + Send + Sync + ?Sized,
+        F: FnMut(Option<&V>) + Send,
+    {
+        let shard = self.shard_for(key).read();
+        match shard.get(key) {
+            Some(v) => f(Some(&v.lock())),
+            None => f(None),
+        }
+        Ok(())
+    }
+
+    async fn for_each<F>(&self, mut f: F) -> Result<(), Self::Err>
+    where
+        F: FnMut((&K, &V)) + Send,
+    {
+        for shard in &self.shards {
+            let shard = shard.read();
+            shard.iter().for_each(|(k, v)| {
+                let v = v.lock();
+                f((k, &*v));
+            });
+        }
+        Ok(())
+    }
+
+    async fn for_each_mut<F>(&self, mut f: F) -> Result<(), Self::Err>
+    where
+        F: FnMut((&K, &mut V)) + Send,
+    {
+        for shard in &self.shards {
+            let shard = shard.read();
+            shard.iter().for_each(|(k, v)| {
+                let mut v = v.lock();
+                f((k, &mut *v));
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_shardedmap() {
+    let kvstore = ShardedMap::with_shards(4);
+    crate::test::test_impl(&kvstore).await;
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_shardedmap_spreads_keys_across_shards() {
+    let kvstore: ShardedMap<usize, usize> = ShardedMap::with_shards(4);
+    for key in 0..64 {
+        kvstore.insert(key, key).await.unwrap();
+    }
+
+    assert_eq!(kvstore.len().await, 64);
+    let occupied = kvstore
+        .shards
+        .iter()
+        .filter(|shard| !shard.read().is_empty())
+        .count();
+    assert!(occupied > 1, "expected keys to spread across more than one shard");
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_shardedmap_concurrent_insert() {
+    use std::sync::Arc;
+
+    let kvstore = Arc::new(ShardedMap::<usize, usize>::with_shards(8));
+    let handles: Vec<_> = (0..100)
+        .map(|key| {
+            let kvstore = kvstore.clone();
+            tokio::spawn(async move {
+                kvstore.insert(key, key * 2).await.unwrap();
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.await.unwrap();
+    }
+
+    assert_eq!(kvstore.len().await, 100);
+    assert!(!kvstore.is_empty().await);
+}
@@ -14,12 +14,18 @@ macro_rules! simple_mutmap_impl {
                 Ok(self.insert(key, value))
             }
 
-            fn remove(&mut self, key: &K) -> Result<Option<V>, Self::Err> {
+            fn remove<Q>(&mut self, key: &Q) -> Result<Option<V>, Self::Err>
+            where
+                K: std::borrow::Borrow<Q>,
+                Q: Hash + Eq + Ord + ?Sized,
+            {
                 Ok(self.remove(key))
             }
 
-            fn mutate<F>(&mut self, key: &K, mut f: F) -> Result<(), Infallible>
+            fn mutate<Q, F>(&mut self, key: &Q, mut f: F) -> Result<(), Infallible>
             where
+                K: std::borrow::Borrow<Q>,
+                Q: Hash + Eq + Ord + ?Sized,
                 F: FnMut(Option<&mut V>),
             {
                 let v = self.get_mut(key);
@@ -27,8 +33,10 @@ macro_rules! simple_mutmap_impl {
                 Ok(())
             }
 
-            fn inspect<F>(&self, key: &K, mut f: F) -> Result<(), Self::Err>
+            fn inspect<Q, F>(&self, key: &Q, mut f: F) -> Result<(), Self::Err>
             where
+                K: std::borrow::Borrow<Q>,
+                Q: Hash + Eq + Ord + ?Sized,
                 F: FnMut(Option<&V>),
             {
                 let v = self.get(key);
@@ -36,7 +44,11 @@ macro_rules! simple_mutmap_impl {
                 Ok(())
             }
 
-            fn contains(&self, key: &K) -> Result<bool, Self::Err> {
+            fn contains<Q>(&self, key: &Q) -> Result<bool, Self::Err>
+            where
+                K: std::borrow::Borrow<Q>,
+                Q: Hash + Eq + Ord + ?Sized,
+            {
                 Ok(self.contains_key(key))
             }
 
@@ -1,5 +1,5 @@
 use crate::traits::{KeyValueStore, MutKeyValueStore};
-use std::{error::Error, sync::Arc};
+use std::{borrow::Borrow, error::Error, hash::Hash, sync::Arc};
 impl<T, K, V> KeyValueStore<K, V> for Arc<T>
 where
     T: KeyValueStore<K, V, Err = Box<dyn Error>>,
@@ -10,16 +10,26 @@ where
         Ok(T::insert(&self, key, value)?)
     }
 
-    fn remove(&self, key: &K) -> Result<Option<V>, Self::Err> {
+    fn remove<Q>(&self, key: &Q) -> Result<Option<V>, Self::Err>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Ord + ?Sized,
+    {
         Ok(T::remove(&self, key)?)
     }
 
-    fn contains(&self, key: &K) -> Result<bool, Self::Err> {
+    fn contains<Q>(&self, key: &Q) -> Result<bool, Self::Err>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Ord + ?Sized,
+    {
         Ok(T::contains(&self, key)?)
     }
 
-    fn mutate<F>(&self, key: &K, f: F) -> Result<(), Self::Err>
+    fn mutate<Q, F>(&self, key: &Q, f: F) -> Result<(), Self::Err>
     where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Ord + ?Sized,
         F: FnMut(Option<&mut V>),
     {
         Ok(T::mutate(self, key, f)?)
@@ -36,8 +46,10 @@ where
         Ok(T::for_each_mut(self, f)?)
     }
 
-    fn inspect<F>(&self, key: &K, f: F) -> Result<(), Self::Err>
+    fn inspect<Q, F>(&self, key: &Q, f: F) -> Result<(), Self::Err>
     where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Ord + ?Sized,
         F: FnMut(Option<&V>),
     {
         Ok(T::inspect(self, key, f)?)
@@ -54,16 +66,26 @@ where
         Ok(T::insert(self, key, value)?)
     }
 
-    fn remove(&mut self, key: &K) -> Result<Option<V>, Self::Err> {
+    fn remove<Q>(&mut self, key: &Q) -> Result<Option<V>, Self::Err>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Ord + ?Sized,
+    {
         Ok(T::remove(self, key)?)
     }
 
-    fn contains(&self, key: &K) -> Result<bool, Self::Err> {
+    fn contains<Q>(&self, key: &Q) -> Result<bool, Self::Err>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Ord + ?Sized,
+    {
         Ok(T::contains(self, key)?)
     }
 
-    fn mutate<F>(&mut self, key: &K, f: F) -> Result<(), Self::Err>
+    fn mutate<Q, F>(&mut self, key: &Q, f: F) -> Result<(), Self::Err>
     where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Ord + ?Sized,
         F: FnMut(Option<&mut V>),
     {
         Ok(T::mutate(self, key, f)?)
@@ -80,8 +102,10 @@ where
         Ok(T::for_each_mut(&self, f)?)
     }
 
-    fn inspect<F>(&self, key: &K, f: F) -> Result<(), Self::Err>
+    fn inspect<Q, F>(&self, key: &Q, f: F) -> Result<(), Self::Err>
     where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Ord + ?Sized,
         F: FnMut(Option<&V>),
     {
         Ok(T::inspect(self, key, f)?)
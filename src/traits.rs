@@ -1,11 +1,13 @@
 use async_trait::async_trait;
+use std::borrow::Borrow;
 use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
 
 /// trait to give the most basic of key value store functionality to immutable objects
 #[async_trait]
 pub trait KeyValueStore<K, V>
 where
-    K: Send + Sync,
+    K: Send + Sync + Hash + Eq + Ord,
     V: Send,
 {
     type Err;
@@ -19,20 +21,30 @@ where
     async fn insert(&self, key: K, value: V) -> Result<Option<V>, Self::Err>;
 
     /// Removes an entry from the map, returning the key and value if they existed in the map.
-    async fn remove(&self, key: &K) -> Result<Option<V>, Self::Err>;
+    async fn remove<Q>(&self, key: &Q) -> Result<Option<V>, Self::Err>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Ord + Send + Sync + ?Sized;
 
     /// Checks if the map contains a specific key.
-    async fn contains(&self, key: &K) -> Result<bool, Self::Err>;
+    async fn contains<Q>(&self, key: &Q) -> Result<bool, Self::Err>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Ord + Send + Sync + ?Sized;
 
     /// Lets you run a function on the specified key
-    async fn inspect<F>(&self, key: &K, f: F) -> Result<(), Self::Err>
+    async fn inspect<Q, F>(&self, key: &Q, f: F) -> Result<(), Self::Err>
     where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Ord + Send + Sync + ?Sized,
         F: FnMut(Option<&V>) + Send;
 
     /// runs a function that can mutate the value if it exists
     // returns true, if the value was mutated
-    async fn get_mut<F>(&self, key: &K, f: F) -> Result<bool, Self::Err>
+    async fn get_mut<Q, F>(&self, key: &Q, f: F) -> Result<bool, Self::Err>
     where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Ord + Send + Sync + ?Sized,
         F: FnMut(&mut V) + Send;
 
     /// mutates the value if it exists with the fiven FnMut, or inserts default, then mutates it
@@ -76,7 +88,7 @@ pub trait GetOwned<K, V> {
 impl<T, K, V> GetOwned<K, V> for T
 where
     T: KeyValueStore<K, V, Err = Box<dyn std::error::Error>> + Send + Sync,
-    K: Send + Sync,
+    K: Send + Sync + Hash + Eq + Ord,
     V: Clone + Send,
 {
     type Err = Box<dyn std::error::Error>;
@@ -103,7 +115,7 @@ impl<T, K, V> GetBTreeMap<K, V> for T
 where
     T: KeyValueStore<K, V, Err = Box<dyn std::error::Error>> + Send + Sync,
     V: Clone + Send,
-    K: Clone + Ord + Send + Sync,
+    K: Clone + Hash + Ord + Send + Sync,
 {
     type Err = Box<dyn std::error::Error>;
     async fn btreemap(&self) -> Result<BTreeMap<K, V>, Self::Err> {
@@ -128,7 +140,7 @@ impl<T, K, V> GetHashMap<K, V> for T
 where
     T: KeyValueStore<K, V, Err = Box<dyn std::error::Error>> + Send + Sync,
     V: Clone + Send,
-    K: Clone + std::hash::Hash + Eq + Send + Sync,
+    K: Clone + std::hash::Hash + Eq + Ord + Send + Sync,
 {
     type Err = Box<dyn std::error::Error>;
     async fn hashmap(&self) -> Result<HashMap<K, V>, Self::Err> {